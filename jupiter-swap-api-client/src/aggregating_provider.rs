@@ -0,0 +1,144 @@
+//! Fans a single quote request out to every registered `SwapProvider` concurrently and
+//! routes to whichever one currently offers the best execution.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use futures::future::join_all;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::quote::{QuoteRequest, QuoteResponse, SwapMode};
+use crate::swap::SwapInstructionsResponse;
+use crate::swap_provider::SwapProvider;
+
+/// Aggregates multiple `SwapProvider`s behind a single `SwapProvider` interface, always
+/// quoting every backend concurrently and returning the best-execution response. This
+/// gives callers best-execution across aggregators without changing their call site.
+pub struct AggregatingProvider {
+    providers: Vec<Box<dyn SwapProvider>>,
+}
+
+impl AggregatingProvider {
+    pub fn new(providers: Vec<Box<dyn SwapProvider>>) -> Self {
+        Self { providers }
+    }
+
+    /// Picks the better of two quotes for the given `swap_mode`: highest `out_amount`
+    /// for `ExactIn`, lowest `in_amount` for `ExactOut`.
+    fn is_better(swap_mode: &SwapMode, candidate: &QuoteResponse, current_best: &QuoteResponse) -> bool {
+        match swap_mode {
+            SwapMode::ExactIn => candidate.out_amount > current_best.out_amount,
+            SwapMode::ExactOut => candidate.in_amount < current_best.in_amount,
+        }
+    }
+
+    /// Returns the provider whose label matches `provider`, i.e. the one that produced
+    /// a previously returned best quote.
+    fn provider_for_label(&self, provider: &str) -> Result<&dyn SwapProvider> {
+        self.providers
+            .iter()
+            .map(|candidate| candidate.as_ref())
+            .find(|candidate| candidate.label() == provider)
+            .ok_or_else(|| anyhow!("no registered provider matches route plan provider '{provider}'"))
+    }
+}
+
+#[async_trait]
+impl SwapProvider for AggregatingProvider {
+    fn label(&self) -> &str {
+        "Aggregator"
+    }
+
+    async fn quote(&self, req: &QuoteRequest) -> Result<QuoteResponse> {
+        let quotes = join_all(self.providers.iter().map(|provider| provider.quote(req))).await;
+        let swap_mode = req.swap_mode.clone().unwrap_or_default();
+
+        let (winning_label, mut best) = self
+            .providers
+            .iter()
+            .zip(quotes)
+            .filter_map(|(provider, quote)| quote.ok().map(|quote| (provider.label(), quote)))
+            .reduce(|best, candidate| {
+                if Self::is_better(&swap_mode, &candidate.1, &best.1) {
+                    candidate
+                } else {
+                    best
+                }
+            })
+            .ok_or_else(|| anyhow!("no provider returned a quote"))?;
+
+        // Tag the winning route plan with the provider that produced it, so
+        // `swap_instructions` can route back to it later. This is recorded in
+        // `RoutePlanStep::provider`, a field distinct from `swap_info.label`, so the
+        // real AMM/venue label (e.g. "Raydium") is preserved.
+        for step in &mut best.route_plan {
+            step.provider = Some(winning_label.to_string());
+        }
+
+        Ok(best)
+    }
+
+    async fn swap_instructions(
+        &self,
+        quote_response: &QuoteResponse,
+        user_public_key: Pubkey,
+    ) -> Result<SwapInstructionsResponse> {
+        let provider = quote_response
+            .route_plan
+            .first()
+            .and_then(|step| step.provider.as_deref())
+            .unwrap_or_default();
+        self.provider_for_label(provider)?
+            .swap_instructions(quote_response, user_public_key)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::pubkey::Pubkey;
+
+    use super::*;
+
+    fn quote_response(in_amount: u64, out_amount: u64) -> QuoteResponse {
+        QuoteResponse {
+            input_mint: Pubkey::default(),
+            in_amount,
+            output_mint: Pubkey::default(),
+            out_amount,
+            other_amount_threshold: out_amount,
+            swap_mode: SwapMode::ExactIn,
+            slippage_bps: 50,
+            platform_fee: None,
+            price_impact_pct: Default::default(),
+            route_plan: vec![],
+            context_slot: 0,
+            time_taken: 0.0,
+        }
+    }
+
+    #[test]
+    fn exact_in_prefers_higher_out_amount() {
+        let worse = quote_response(100, 90);
+        let better = quote_response(100, 95);
+
+        assert!(AggregatingProvider::is_better(&SwapMode::ExactIn, &better, &worse));
+        assert!(!AggregatingProvider::is_better(&SwapMode::ExactIn, &worse, &better));
+    }
+
+    #[test]
+    fn exact_out_prefers_lower_in_amount() {
+        let worse = quote_response(100, 90);
+        let better = quote_response(90, 90);
+
+        assert!(AggregatingProvider::is_better(
+            &SwapMode::ExactOut,
+            &better,
+            &worse
+        ));
+        assert!(!AggregatingProvider::is_better(
+            &SwapMode::ExactOut,
+            &worse,
+            &better
+        ));
+    }
+}
@@ -0,0 +1,107 @@
+//! A thin async client for Jupiter's swap-api (quote + swap-instructions endpoints).
+
+use anyhow::Context;
+use async_trait::async_trait;
+use reqwest::Client;
+use solana_sdk::pubkey::Pubkey;
+
+use quote::{QuoteRequest, QuoteResponse};
+use response::JupiterResponse;
+use swap::{SwapInstructionsResponse, SwapRequest};
+use swap_provider::SwapProvider;
+
+pub mod aggregating_provider;
+pub mod quote;
+pub mod quote_stream;
+pub mod rate_tracker;
+pub mod response;
+pub mod route_plan_with_metadata;
+pub mod sanctum;
+pub mod serde_helpers;
+pub mod submission_queue;
+pub mod swap;
+pub mod swap_provider;
+
+/// Label this client reports through `SwapProvider::label`. Its own `quote` leaves route-plan
+/// steps untagged; `AggregatingProvider` tags a winning quote's steps with this label.
+pub const JUPITER_LABEL: &str = "Jupiter";
+
+/// Client for a Jupiter swap-api instance (the hosted API, a self-hosted instance,
+/// or any other server implementing the same HTTP contract).
+pub struct JupiterSwapApiClient {
+    pub base_path: String,
+}
+
+impl JupiterSwapApiClient {
+    pub fn new(base_path: String) -> Self {
+        Self { base_path }
+    }
+
+    /// Requests a quote for the given `QuoteRequest` from the `/quote` endpoint. The
+    /// response is wrapped in [`JupiterResponse`] so callers can inspect the slot the
+    /// quote was computed against (when the server reports one) before acting on it.
+    pub async fn quote(
+        &self,
+        quote_request: &QuoteRequest,
+    ) -> anyhow::Result<JupiterResponse<QuoteResponse>> {
+        let url = format!("{}/quote", self.base_path);
+        let response = Client::new()
+            .get(url)
+            .query(quote_request)
+            .send()
+            .await?
+            .text()
+            .await?;
+        serde_json::from_str::<JupiterResponse<QuoteResponse>>(&response)
+            .with_context(|| format!("Failed to deserialize response: {response}"))
+    }
+
+    /// Builds the instructions needed to execute `quote_response` from the
+    /// `/swap-instructions` endpoint. Wrapped in [`JupiterResponse`] for the same
+    /// reason as `quote`, even though the server rarely attaches context to this
+    /// particular endpoint.
+    pub async fn swap_instructions(
+        &self,
+        quote_response: &QuoteResponse,
+        user_public_key: Pubkey,
+    ) -> anyhow::Result<JupiterResponse<SwapInstructionsResponse>> {
+        let url = format!("{}/swap-instructions", self.base_path);
+        let swap_request = SwapRequest {
+            user_public_key,
+            quote_response: quote_response.clone(),
+            wrap_and_unwrap_sol: Some(true),
+        };
+        let response = Client::new()
+            .post(url)
+            .json(&swap_request)
+            .send()
+            .await?
+            .text()
+            .await?;
+        serde_json::from_str::<JupiterResponse<SwapInstructionsResponse>>(&response)
+            .with_context(|| format!("Failed to deserialize response: {response}"))
+    }
+}
+
+#[async_trait]
+impl SwapProvider for JupiterSwapApiClient {
+    fn label(&self) -> &str {
+        JUPITER_LABEL
+    }
+
+    async fn quote(&self, req: &QuoteRequest) -> anyhow::Result<QuoteResponse> {
+        Ok(JupiterSwapApiClient::quote(self, req).await?.parse_value())
+    }
+
+    async fn swap_instructions(
+        &self,
+        quote_response: &QuoteResponse,
+        user_public_key: Pubkey,
+    ) -> anyhow::Result<SwapInstructionsResponse> {
+        Ok(
+            JupiterSwapApiClient::swap_instructions(self, quote_response, user_public_key)
+                .await?
+                .parse_value(),
+        )
+    }
+}
@@ -4,7 +4,7 @@
 use std::str::FromStr;
 
 use crate::route_plan_with_metadata::RoutePlanWithMetadata;
-use crate::serde_helpers::field_as_string;
+use crate::serde_helpers::{field_as_string, number_or_string};
 use anyhow::{anyhow, Error};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -178,7 +178,7 @@ impl From<QuoteRequest> for InternalQuoteRequest {
 /// Details about the platform fee collected for the swap.
 pub struct PlatformFee {
     /// The fee amount collected (factoring in token decimals).
-    #[serde(with = "field_as_string")]
+    #[serde(with = "number_or_string")]
     pub amount: u64,
     /// The fee percentage collected, in basis points (BPS).
     pub fee_bps: u16,
@@ -194,17 +194,17 @@ pub struct QuoteResponse {
     #[serde(with = "field_as_string")]
     pub input_mint: Pubkey,
     /// The final input amount needed for the route (may differ slightly if SwapMode::ExactOut).
-    #[serde(with = "field_as_string")]
+    #[serde(with = "number_or_string")]
     pub in_amount: u64,
     /// The mint of the token to be received by the user.
     #[serde(with = "field_as_string")]
     pub output_mint: Pubkey,
     /// The final output amount expected from the route (may differ slightly if SwapMode::ExactIn).
-    #[serde(with = "field_as_string")]
+    #[serde(with = "number_or_string")]
     pub out_amount: u64,
     /// The threshold amount on the non-fixed side of the swap. Used for validation/slippage.
     /// (e.g., minimum out for ExactIn, maximum in for ExactOut).
-    #[serde(with = "field_as_string")]
+    #[serde(with = "number_or_string")]
     pub other_amount_threshold: u64,
     /// The mode used for calculating the quote (ExactIn or ExactOut).
     pub swap_mode: SwapMode,
@@ -0,0 +1,132 @@
+//! Polls a `SwapProvider` for quotes at a fixed interval and yields typed price
+//! updates, mirroring how ticker feeds surface bid/ask deltas, so bots can react to
+//! price moves without reimplementing retry and slot-dedup logic themselves. A failed
+//! quote is retried on the next tick; the interval stays fixed, with no backoff.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use rust_decimal::Decimal;
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::mpsc;
+use tokio::time;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::Stream;
+
+use crate::quote::QuoteRequest;
+use crate::swap_provider::SwapProvider;
+
+/// A single price observation emitted by a `QuoteStream`.
+#[derive(Debug, Clone)]
+pub struct PriceUpdate {
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    /// The implied price, `out_amount / in_amount`.
+    pub price: Decimal,
+    pub out_amount: u64,
+    /// The slot the underlying quote was computed against.
+    pub context_slot: u64,
+    /// Set when `price` moved beyond the configured threshold since the previous update.
+    pub changed: bool,
+}
+
+/// The implied price of a quote: `out_amount / in_amount`.
+fn implied_price(out_amount: u64, in_amount: u64) -> Decimal {
+    Decimal::from(out_amount) / Decimal::from(in_amount.max(1))
+}
+
+/// Whether `price` moved more than `threshold_bps` away from `previous`. Always `false`
+/// with no previous price (the first update in a stream) or a zero previous price.
+fn price_changed(previous: Option<Decimal>, price: Decimal, threshold_bps: u32) -> bool {
+    match previous {
+        Some(previous) if !previous.is_zero() => {
+            let delta_bps = ((price - previous) / previous).abs() * Decimal::from(10_000);
+            delta_bps > Decimal::from(threshold_bps)
+        }
+        _ => false,
+    }
+}
+
+/// Polls a provider for quotes on a fixed `QuoteRequest` and turns them into a stream of
+/// `PriceUpdate`s, deduped by `context_slot` so consumers only see genuinely new slots.
+/// A `context_slot` of 0 means no slot was reported and is never deduped.
+pub struct QuoteStream;
+
+impl QuoteStream {
+    /// Spawns the polling loop and returns a `Stream` of price updates. `request` is
+    /// reissued every `interval`; `change_threshold_bps` controls when
+    /// `PriceUpdate::changed` is set, i.e. the implied price must move by more than that
+    /// many basis points relative to the previous update.
+    pub fn new(
+        provider: Arc<dyn SwapProvider>,
+        request: QuoteRequest,
+        interval: Duration,
+        change_threshold_bps: u32,
+    ) -> impl Stream<Item = PriceUpdate> {
+        let (tx, rx) = mpsc::channel(16);
+
+        tokio::spawn(async move {
+            let mut ticker = time::interval(interval);
+            let mut last_context_slot: Option<u64> = None;
+            let mut last_price: Option<Decimal> = None;
+
+            loop {
+                ticker.tick().await;
+
+                // Transient RPC/HTTP failures are retried on the next tick rather than
+                // tearing down the stream.
+                let Ok(quote) = provider.quote(&request).await else {
+                    continue;
+                };
+
+                if quote.context_slot != 0 && last_context_slot == Some(quote.context_slot) {
+                    continue;
+                }
+                last_context_slot = Some(quote.context_slot);
+
+                let price = implied_price(quote.out_amount, quote.in_amount);
+                let changed = price_changed(last_price, price, change_threshold_bps);
+                last_price = Some(price);
+
+                let update = PriceUpdate {
+                    input_mint: request.input_mint,
+                    output_mint: request.output_mint,
+                    price,
+                    out_amount: quote.out_amount,
+                    context_slot: quote.context_slot,
+                    changed,
+                };
+
+                if tx.send(update).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        ReceiverStream::new(rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn implied_price_is_out_over_in() {
+        assert_eq!(implied_price(150, 100), Decimal::new(15, 1));
+    }
+
+    #[test]
+    fn first_update_never_reports_changed() {
+        assert!(!price_changed(None, Decimal::new(11, 1), 50));
+    }
+
+    #[test]
+    fn reports_changed_only_past_the_threshold() {
+        let previous = Decimal::ONE;
+        // A 0.4% move is below a 50 bps (0.5%) threshold.
+        assert!(!price_changed(Some(previous), Decimal::new(1004, 3), 50));
+        // A 0.6% move is past it.
+        assert!(price_changed(Some(previous), Decimal::new(1006, 3), 50));
+    }
+}
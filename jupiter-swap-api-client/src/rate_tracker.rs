@@ -0,0 +1,202 @@
+//! Tracks realized volatility for a mint pair from periodic reference quotes, so
+//! callers can size `slippage_bps` to live market conditions instead of a static value.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use solana_sdk::pubkey::Pubkey;
+
+use crate::quote::QuoteRequest;
+use crate::swap_provider::SwapProvider;
+
+/// A single observed price sample: the implied price of a reference quote and when it
+/// was recorded locally. The sample does not store its slot; `RateTracker` keeps only
+/// the last slot it has seen.
+#[derive(Debug, Clone, Copy)]
+struct PriceSample {
+    price: f64,
+    observed_at: Instant,
+}
+
+/// Tracks the realized volatility of a mint pair's implied price (`out_amount /
+/// in_amount`) over a fixed-size rolling window of reference quotes, and recommends a
+/// slippage budget from it.
+pub struct RateTracker {
+    input_mint: Pubkey,
+    output_mint: Pubkey,
+    reference_amount: u64,
+    window: VecDeque<PriceSample>,
+    window_size: usize,
+    last_context_slot: Option<u64>,
+    stale_after: Duration,
+    ceiling_bps: u16,
+}
+
+impl RateTracker {
+    /// Creates a tracker for `input_mint`/`output_mint`, sampling with a fixed
+    /// `reference_amount` as the input, keeping up to `window_size` samples.
+    pub fn new(
+        input_mint: Pubkey,
+        output_mint: Pubkey,
+        reference_amount: u64,
+        window_size: usize,
+    ) -> Self {
+        Self {
+            input_mint,
+            output_mint,
+            reference_amount,
+            window: VecDeque::with_capacity(window_size),
+            window_size,
+            last_context_slot: None,
+            stale_after: Duration::from_secs(30),
+            ceiling_bps: 1_000,
+        }
+    }
+
+    /// Overrides how long the window can go without observing a new slot before it's
+    /// considered stale. Defaults to 30 seconds.
+    pub fn with_stale_after(mut self, stale_after: Duration) -> Self {
+        self.stale_after = stale_after;
+        self
+    }
+
+    /// Overrides the ceiling `recommended_slippage_bps` clamps its recommendation to.
+    /// Defaults to 1,000 bps (10%).
+    pub fn with_ceiling_bps(mut self, ceiling_bps: u16) -> Self {
+        self.ceiling_bps = ceiling_bps;
+        self
+    }
+
+    /// Issues a reference quote through `provider` and records its implied price, if
+    /// the quote's `context_slot` is new. Stale (repeated) slots are ignored so a
+    /// provider polled faster than it refreshes doesn't manufacture fake volatility. A
+    /// `context_slot` of 0 means no slot was reported and is never treated as repeated.
+    pub async fn sample(&mut self, provider: &dyn SwapProvider) -> anyhow::Result<()> {
+        let request = QuoteRequest {
+            input_mint: self.input_mint,
+            output_mint: self.output_mint,
+            amount: self.reference_amount,
+            ..Default::default()
+        };
+        let quote = provider.quote(&request).await?;
+
+        if quote.context_slot != 0 && self.last_context_slot == Some(quote.context_slot) {
+            return Ok(());
+        }
+        self.last_context_slot = Some(quote.context_slot);
+
+        let price = quote.out_amount as f64 / quote.in_amount as f64;
+        if self.window.len() == self.window_size {
+            self.window.pop_front();
+        }
+        self.window.push_back(PriceSample {
+            price,
+            observed_at: Instant::now(),
+        });
+        Ok(())
+    }
+
+    /// Realized volatility: the standard deviation of log-returns between consecutive
+    /// samples in the window. `None` with fewer than two samples.
+    fn realized_volatility(&self) -> Option<f64> {
+        if self.window.len() < 2 {
+            return None;
+        }
+        let log_returns: Vec<f64> = self
+            .window
+            .iter()
+            .zip(self.window.iter().skip(1))
+            .map(|(prev, next)| (next.price / prev.price).ln())
+            .collect();
+        let mean = log_returns.iter().sum::<f64>() / log_returns.len() as f64;
+        let variance =
+            log_returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / log_returns.len() as f64;
+        Some(variance.sqrt())
+    }
+
+    /// Whether the most recent sample is older than `stale_after`, i.e. no new slot has
+    /// come in recently enough to vouch for the market having stayed calm.
+    fn is_stale(&self) -> bool {
+        self.window
+            .back()
+            .map(|sample| sample.observed_at.elapsed() > self.stale_after)
+            .unwrap_or(false)
+    }
+
+    /// Recommends a `slippage_bps` from `base_bps` and a sensitivity multiplier `k`:
+    /// `base_bps + k * volatility * 10_000`, clamped to `ceiling_bps` (see
+    /// `with_ceiling_bps`).
+    ///
+    /// Edge cases: fewer than two samples returns `base_bps` unchanged, and a stale
+    /// window (no new slot within `stale_after`) widens slippage to double `base_bps`
+    /// rather than trusting the last-known (possibly outdated) volatility reading.
+    pub fn recommended_slippage_bps(&self, base_bps: u16, k: f64) -> u16 {
+        let Some(volatility) = self.realized_volatility() else {
+            return base_bps;
+        };
+
+        let recommended = if self.is_stale() {
+            base_bps as f64 * 2.0
+        } else {
+            base_bps as f64 + k * volatility * 10_000.0
+        };
+
+        recommended.round().clamp(0.0, self.ceiling_bps as f64) as u16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tracker() -> RateTracker {
+        RateTracker::new(Pubkey::default(), Pubkey::default(), 1_000_000, 8)
+    }
+
+    fn push_price(tracker: &mut RateTracker, price: f64, observed_at: Instant) {
+        tracker.window.push_back(PriceSample { price, observed_at });
+    }
+
+    #[test]
+    fn fewer_than_two_samples_returns_base_bps() {
+        let mut tracker = tracker();
+        assert_eq!(tracker.recommended_slippage_bps(50, 1.0), 50);
+
+        push_price(&mut tracker, 1.0, Instant::now());
+        assert_eq!(tracker.recommended_slippage_bps(50, 1.0), 50);
+    }
+
+    #[test]
+    fn widens_with_realized_volatility() {
+        let mut tracker = tracker();
+        let now = Instant::now();
+        push_price(&mut tracker, 1.0, now);
+        push_price(&mut tracker, 1.1, now);
+        push_price(&mut tracker, 1.0, now);
+
+        let recommended = tracker.recommended_slippage_bps(50, 1.0);
+        assert!(recommended > 50, "expected widened slippage, got {recommended}");
+    }
+
+    #[test]
+    fn stale_window_widens_instead_of_trusting_last_volatility() {
+        let mut tracker = tracker().with_stale_after(Duration::from_millis(0));
+        let now = Instant::now();
+        // A flat price (zero volatility) would otherwise recommend exactly `base_bps`.
+        push_price(&mut tracker, 1.0, now);
+        push_price(&mut tracker, 1.0, now);
+
+        assert_eq!(tracker.recommended_slippage_bps(50, 1.0), 100);
+    }
+
+    #[test]
+    fn clamps_to_ceiling() {
+        let mut tracker = tracker().with_ceiling_bps(60);
+        let now = Instant::now();
+        push_price(&mut tracker, 1.0, now);
+        push_price(&mut tracker, 2.0, now);
+        push_price(&mut tracker, 1.0, now);
+
+        assert_eq!(tracker.recommended_slippage_bps(50, 10.0), 60);
+    }
+}
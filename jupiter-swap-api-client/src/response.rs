@@ -0,0 +1,71 @@
+//! Generic response envelope used by endpoints that may attach network context
+//! (slot, API version) to their payload.
+
+use serde::{Deserialize, Serialize};
+
+/// Network context accompanying a response, mirroring Solana RPC's `RpcResponseContext`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseContext {
+    /// The slot the response was generated against.
+    pub slot: u64,
+    /// The API version reported by the server, if any.
+    pub api_version: Option<String>,
+}
+
+/// A response that may or may not be accompanied by [`ResponseContext`], modeled on
+/// Solana RPC's `OptionalContext`. Lets callers tell a response that came with network
+/// context (and can thus be checked for staleness) apart from one that didn't, without
+/// forcing every endpoint to always carry the same envelope fields.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum JupiterResponse<T> {
+    Context { context: ResponseContext, value: T },
+    NoContext(T),
+}
+
+impl<T> JupiterResponse<T> {
+    /// Discards any attached context and returns the inner value.
+    pub fn parse_value(self) -> T {
+        match self {
+            JupiterResponse::Context { value, .. } => value,
+            JupiterResponse::NoContext(value) => value,
+        }
+    }
+
+    /// Returns the attached context, if any.
+    pub fn context(&self) -> Option<&ResponseContext> {
+        match self {
+            JupiterResponse::Context { context, .. } => Some(context),
+            JupiterResponse::NoContext(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_context_variant() {
+        let json = r#"{"context":{"slot":123,"apiVersion":"1.2.3"},"value":42}"#;
+        let response: JupiterResponse<u32> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            response.context(),
+            Some(&ResponseContext {
+                slot: 123,
+                api_version: Some("1.2.3".to_string()),
+            })
+        );
+        assert_eq!(response.parse_value(), 42);
+    }
+
+    #[test]
+    fn deserializes_no_context_variant() {
+        let response: JupiterResponse<u32> = serde_json::from_str("42").unwrap();
+
+        assert_eq!(response.context(), None);
+        assert_eq!(response.parse_value(), 42);
+    }
+}
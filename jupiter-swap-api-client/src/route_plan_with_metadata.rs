@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
 
-use crate::serde_helpers::field_as_string;
+use crate::serde_helpers::{field_as_string, number_or_string};
 
 /// Topologically sorted DAG with additional metadata for rendering
 pub type RoutePlanWithMetadata = Vec<RoutePlanStep>;
@@ -14,6 +14,12 @@ pub struct RoutePlanStep {
     /// BPS value (may not be present in lite API responses)
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub bps: Option<u16>,
+    /// The aggregator-level provider that produced this route (e.g. "Jupiter",
+    /// "Sanctum"), as opposed to `swap_info.label`, which names the underlying AMM/venue
+    /// for this hop. Not part of any upstream API response; set locally by things like
+    /// `AggregatingProvider` so `swap_instructions` can route back to the right backend.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub provider: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
@@ -27,10 +33,10 @@ pub struct SwapInfo {
     #[serde(with = "field_as_string")]
     pub output_mint: Pubkey,
     /// An estimation of the input amount into the AMM
-    #[serde(with = "field_as_string")]
+    #[serde(with = "number_or_string")]
     pub in_amount: u64,
     /// An estimation of the output amount into the AMM
-    #[serde(with = "field_as_string")]
+    #[serde(with = "number_or_string")]
     pub out_amount: u64,
     /// Fee amount (may not be present in lite API responses)
     #[serde(
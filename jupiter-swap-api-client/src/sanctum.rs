@@ -0,0 +1,139 @@
+//! A `SwapProvider` backed by Sanctum's swap API, specialized for liquid-staking-token
+//! (LST) swaps (e.g. mSOL/JitoSOL/bSOL against SOL or each other).
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::quote::{PlatformFee, QuoteRequest, QuoteResponse, SwapMode};
+use crate::route_plan_with_metadata::{RoutePlanStep, SwapInfo};
+use crate::serde_helpers::number_or_string;
+use crate::swap::{SwapInstructionsResponse, SwapRequest};
+use crate::swap_provider::SwapProvider;
+
+/// Sanctum's own quote response shape, which is narrower than Jupiter's since an LST
+/// swap only ever involves a single hop through a stake pool.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct SanctumQuoteResponse {
+    #[serde(with = "number_or_string")]
+    in_amount: u64,
+    #[serde(with = "number_or_string")]
+    out_amount: u64,
+    #[serde(with = "number_or_string")]
+    fee_amount: u64,
+    fee_mint: Pubkey,
+    /// The slot Sanctum computed this quote against. Defaults to `0` only if the
+    /// server omits it, which downstream consumers (`QuoteStream`, `RateTracker`) treat
+    /// as "no slot known" rather than a real, dedupable slot.
+    #[serde(default)]
+    context_slot: u64,
+}
+
+/// Label used to tag route-plan steps produced by this provider, and to route
+/// `swap_instructions` calls for a winning `AggregatingProvider` quote back here.
+pub const SANCTUM_LABEL: &str = "Sanctum";
+
+pub struct SanctumSwapProvider {
+    pub base_path: String,
+}
+
+impl SanctumSwapProvider {
+    pub fn new(base_path: String) -> Self {
+        Self { base_path }
+    }
+}
+
+#[async_trait]
+impl SwapProvider for SanctumSwapProvider {
+    fn label(&self) -> &str {
+        SANCTUM_LABEL
+    }
+
+    async fn quote(&self, req: &QuoteRequest) -> Result<QuoteResponse> {
+        let url = format!("{}/v1/swap/quote", self.base_path);
+        let response = Client::new()
+            .get(url)
+            .query(&[
+                ("input", req.input_mint.to_string()),
+                ("outputLstMint", req.output_mint.to_string()),
+                ("amount", req.amount.to_string()),
+            ])
+            .send()
+            .await?
+            .text()
+            .await?;
+        let sanctum_response: SanctumQuoteResponse = serde_json::from_str(&response)
+            .with_context(|| format!("Failed to deserialize Sanctum response: {response}"))?;
+
+        let swap_mode = req.swap_mode.clone().unwrap_or_default();
+        let other_amount_threshold = match swap_mode {
+            // Minimum acceptable out: the quoted out_amount minus the allowed slippage.
+            SwapMode::ExactIn => {
+                sanctum_response.out_amount
+                    - sanctum_response.out_amount * req.slippage_bps as u64 / 10_000
+            }
+            // Maximum acceptable in: the quoted in_amount plus the allowed slippage.
+            SwapMode::ExactOut => {
+                sanctum_response.in_amount
+                    + sanctum_response.in_amount * req.slippage_bps as u64 / 10_000
+            }
+        };
+
+        Ok(QuoteResponse {
+            input_mint: req.input_mint,
+            in_amount: sanctum_response.in_amount,
+            output_mint: req.output_mint,
+            out_amount: sanctum_response.out_amount,
+            other_amount_threshold,
+            swap_mode,
+            slippage_bps: req.slippage_bps,
+            platform_fee: Some(PlatformFee {
+                amount: sanctum_response.fee_amount,
+                fee_bps: 0,
+            }),
+            price_impact_pct: Default::default(),
+            route_plan: vec![RoutePlanStep {
+                swap_info: SwapInfo {
+                    amm_key: Pubkey::default(),
+                    label: SANCTUM_LABEL.to_string(),
+                    input_mint: req.input_mint,
+                    output_mint: req.output_mint,
+                    in_amount: sanctum_response.in_amount,
+                    out_amount: sanctum_response.out_amount,
+                    fee_amount: Some(sanctum_response.fee_amount),
+                    fee_mint: Some(sanctum_response.fee_mint),
+                },
+                percent: 100,
+                bps: None,
+                provider: Some(SANCTUM_LABEL.to_string()),
+            }],
+            context_slot: sanctum_response.context_slot,
+            time_taken: 0.0,
+        })
+    }
+
+    async fn swap_instructions(
+        &self,
+        quote_response: &QuoteResponse,
+        user_public_key: Pubkey,
+    ) -> Result<SwapInstructionsResponse> {
+        let url = format!("{}/v1/swap-instructions", self.base_path);
+        let swap_request = SwapRequest {
+            user_public_key,
+            quote_response: quote_response.clone(),
+            wrap_and_unwrap_sol: Some(true),
+        };
+        let response = Client::new()
+            .post(url)
+            .json(&swap_request)
+            .send()
+            .await?
+            .text()
+            .await?;
+        serde_json::from_str(&response)
+            .with_context(|| format!("Failed to deserialize Sanctum response: {response}"))
+    }
+}
@@ -0,0 +1,127 @@
+//! Custom (de)serializers for fields whose JSON representation doesn't map
+//! cleanly onto the Rust type (e.g. large integers and pubkeys sent as strings).
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+/// (De)serializes a type through its `Display`/`FromStr` implementation, represented
+/// in JSON as a string. Used for `Pubkey` and `u64` fields, since JSON numbers can't
+/// safely hold a full `u64` and Jupiter's API represents both as strings.
+pub mod field_as_string {
+    use super::*;
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+/// Like [`field_as_string`], but for `Option<T>` fields that may be entirely absent
+/// from the JSON payload (e.g. fee fields missing from lite API responses).
+pub mod option_field_as_string {
+    use super::*;
+
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        match value {
+            Some(value) => serializer.collect_str(value),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let s: Option<String> = Option::deserialize(deserializer)?;
+        s.map(|s| s.parse().map_err(de::Error::custom)).transpose()
+    }
+}
+
+/// (De)serializes a `u64` that may arrive as either a bare JSON number or a quoted
+/// decimal string, normalizing it to a `u64`. Jupiter's full API always sends amount
+/// fields as strings (to avoid precision loss in JS number parsing), but the lite
+/// endpoints and some proxy layers emit the same fields as JSON integers instead.
+/// Always serializes back out as a string, matching the full API's wire format.
+pub mod number_or_string {
+    use super::*;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString {
+        Number(u64),
+        String(String),
+    }
+
+    pub fn serialize<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match NumberOrString::deserialize(deserializer)? {
+            NumberOrString::Number(n) => Ok(n),
+            NumberOrString::String(s) => s.parse().map_err(de::Error::custom),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::number_or_string;
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Wrapper {
+        #[serde(with = "number_or_string")]
+        amount: u64,
+    }
+
+    #[test]
+    fn accepts_bare_json_number() {
+        let wrapper: Wrapper = serde_json::from_str(r#"{"amount":42}"#).unwrap();
+        assert_eq!(wrapper.amount, 42);
+    }
+
+    #[test]
+    fn accepts_quoted_decimal_string() {
+        let wrapper: Wrapper = serde_json::from_str(r#"{"amount":"42"}"#).unwrap();
+        assert_eq!(wrapper.amount, 42);
+    }
+
+    #[test]
+    fn rejects_non_numeric_string() {
+        let result: Result<Wrapper, _> = serde_json::from_str(r#"{"amount":"not-a-number"}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn always_serializes_as_a_string() {
+        let wrapper = Wrapper { amount: 42 };
+        assert_eq!(serde_json::to_string(&wrapper).unwrap(), r#"{"amount":"42"}"#);
+    }
+}
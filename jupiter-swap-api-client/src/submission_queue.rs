@@ -0,0 +1,291 @@
+//! Client-side resilience layer for quote submissions: records each outgoing request
+//! with a unique id and its outcome, and can replay the ones that errored or timed out
+//! with exponential backoff, so an automated strategy doesn't lose track of which
+//! quotes it never successfully obtained.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use tokio::time::sleep;
+
+use crate::quote::{QuoteRequest, QuoteResponse};
+use crate::swap_provider::SwapProvider;
+
+/// Unique identifier for a submission, assigned in insertion order.
+pub type SubmissionId = u64;
+
+#[derive(Debug, Clone)]
+enum Outcome {
+    Succeeded(QuoteResponse),
+    Failed { attempts: u32 },
+}
+
+struct Submission {
+    request: QuoteRequest,
+    outcome: Outcome,
+}
+
+/// The current status of a tracked submission, as returned by [`SubmissionQueue::status`].
+#[derive(Debug, Clone)]
+pub enum SubmissionStatus<'a> {
+    /// The submission succeeded; carries the `QuoteResponse` it was ultimately resolved to.
+    Succeeded(&'a QuoteResponse),
+    /// The submission is still failing after this many attempts.
+    Failed { attempts: u32 },
+}
+
+/// Configures retry behavior for [`SubmissionQueue::resend`] and `resend_failed`.
+#[derive(Debug, Clone, Copy)]
+pub struct BackoffConfig {
+    pub initial_delay: Duration,
+    pub max_delay: Duration,
+    pub max_attempts: u32,
+}
+
+impl Default for BackoffConfig {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            max_attempts: 5,
+        }
+    }
+}
+
+/// Summary of a `resend_failed` pass. `still_failing` names the exact submissions that
+/// remain unresolved, so a caller can act on (or simply log) which quotes were never
+/// successfully obtained, rather than only knowing how many there were.
+#[derive(Debug, Clone, Default)]
+pub struct ResendSummary {
+    pub attempted: usize,
+    pub succeeded: usize,
+    pub still_failing: Vec<SubmissionId>,
+}
+
+/// Records every outgoing quote submission and its outcome, and can replay the failed
+/// ones on demand.
+pub struct SubmissionQueue {
+    next_id: SubmissionId,
+    submissions: HashMap<SubmissionId, Submission>,
+    backoff: BackoffConfig,
+}
+
+impl SubmissionQueue {
+    pub fn new(backoff: BackoffConfig) -> Self {
+        Self {
+            next_id: 0,
+            submissions: HashMap::new(),
+            backoff,
+        }
+    }
+
+    /// Returns the current status of a tracked submission, or `None` if `id` is unknown.
+    pub fn status(&self, id: SubmissionId) -> Option<SubmissionStatus<'_>> {
+        self.submissions.get(&id).map(|submission| match &submission.outcome {
+            Outcome::Succeeded(response) => SubmissionStatus::Succeeded(response),
+            Outcome::Failed { attempts } => SubmissionStatus::Failed {
+                attempts: *attempts,
+            },
+        })
+    }
+
+    /// Returns the ids of every submission still in a failed state.
+    pub fn failed_ids(&self) -> Vec<SubmissionId> {
+        self.submissions
+            .iter()
+            .filter(|(_, submission)| matches!(submission.outcome, Outcome::Failed { .. }))
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    /// Submits `request` through `provider`, recording its outcome, and returns the id
+    /// assigned to it.
+    pub async fn submit(&mut self, provider: &dyn SwapProvider, request: QuoteRequest) -> SubmissionId {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let outcome = match provider.quote(&request).await {
+            Ok(response) => Outcome::Succeeded(response),
+            Err(_) => Outcome::Failed { attempts: 1 },
+        };
+        self.submissions.insert(id, Submission { request, outcome });
+        id
+    }
+
+    /// Replays a single submission by id, retrying with exponential backoff up to
+    /// `backoff.max_attempts`. Returns `true` if it ultimately succeeded, `false` if the
+    /// id is unknown or every retry failed.
+    pub async fn resend(&mut self, provider: &dyn SwapProvider, id: SubmissionId) -> bool {
+        let Some(submission) = self.submissions.get_mut(&id) else {
+            return false;
+        };
+        if matches!(submission.outcome, Outcome::Succeeded(_)) {
+            return true;
+        }
+
+        let mut attempts = match submission.outcome {
+            Outcome::Failed { attempts } => attempts,
+            Outcome::Succeeded(_) => unreachable!(),
+        };
+        let mut delay = self.backoff.initial_delay;
+
+        while attempts < self.backoff.max_attempts {
+            sleep(delay).await;
+            attempts += 1;
+            delay = (delay * 2).min(self.backoff.max_delay);
+
+            match provider.quote(&submission.request).await {
+                Ok(response) => {
+                    submission.outcome = Outcome::Succeeded(response);
+                    return true;
+                }
+                Err(_) => submission.outcome = Outcome::Failed { attempts },
+            }
+        }
+
+        false
+    }
+
+    /// Replays every submission that's currently in a failed state, draining as many as
+    /// possible given `backoff.max_attempts`.
+    pub async fn resend_failed(&mut self, provider: &dyn SwapProvider) -> ResendSummary {
+        let failed_ids = self.failed_ids();
+
+        let mut summary = ResendSummary {
+            attempted: failed_ids.len(),
+            ..Default::default()
+        };
+
+        for id in failed_ids {
+            if self.resend(provider, id).await {
+                summary.succeeded += 1;
+            } else {
+                summary.still_failing.push(id);
+            }
+        }
+
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use async_trait::async_trait;
+    use solana_sdk::pubkey::Pubkey;
+
+    use super::*;
+    use crate::swap::SwapInstructionsResponse;
+
+    /// A provider that fails its first `fail_count` quotes, then always succeeds.
+    struct FlakyProvider {
+        fail_count: u32,
+        attempts: AtomicU32,
+    }
+
+    #[async_trait]
+    impl SwapProvider for FlakyProvider {
+        fn label(&self) -> &str {
+            "Flaky"
+        }
+
+        async fn quote(&self, req: &QuoteRequest) -> anyhow::Result<QuoteResponse> {
+            let attempt = self.attempts.fetch_add(1, Ordering::SeqCst) + 1;
+            if attempt <= self.fail_count {
+                return Err(anyhow::anyhow!("simulated failure"));
+            }
+            Ok(QuoteResponse {
+                input_mint: req.input_mint,
+                in_amount: req.amount,
+                output_mint: req.output_mint,
+                out_amount: req.amount,
+                other_amount_threshold: req.amount,
+                swap_mode: req.swap_mode.clone().unwrap_or_default(),
+                slippage_bps: req.slippage_bps,
+                platform_fee: None,
+                price_impact_pct: Default::default(),
+                route_plan: vec![],
+                context_slot: 0,
+                time_taken: 0.0,
+            })
+        }
+
+        async fn swap_instructions(
+            &self,
+            _quote_response: &QuoteResponse,
+            _user_public_key: Pubkey,
+        ) -> anyhow::Result<SwapInstructionsResponse> {
+            unimplemented!("not exercised by SubmissionQueue tests")
+        }
+    }
+
+    fn request() -> QuoteRequest {
+        QuoteRequest {
+            amount: 1_000,
+            ..Default::default()
+        }
+    }
+
+    fn fast_backoff(max_attempts: u32) -> BackoffConfig {
+        BackoffConfig {
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+            max_attempts,
+        }
+    }
+
+    #[tokio::test]
+    async fn resend_succeeds_within_attempt_cap() {
+        let provider = FlakyProvider {
+            fail_count: 2,
+            attempts: AtomicU32::new(0),
+        };
+        let mut queue = SubmissionQueue::new(fast_backoff(3));
+        let id = queue.submit(&provider, request()).await;
+        assert!(matches!(
+            queue.status(id),
+            Some(SubmissionStatus::Failed { attempts: 1 })
+        ));
+
+        assert!(queue.resend(&provider, id).await);
+        assert!(matches!(queue.status(id), Some(SubmissionStatus::Succeeded(_))));
+    }
+
+    #[tokio::test]
+    async fn resend_gives_up_after_max_attempts() {
+        let provider = FlakyProvider {
+            fail_count: u32::MAX,
+            attempts: AtomicU32::new(0),
+        };
+        let mut queue = SubmissionQueue::new(fast_backoff(3));
+        let id = queue.submit(&provider, request()).await;
+
+        assert!(!queue.resend(&provider, id).await);
+        assert!(matches!(
+            queue.status(id),
+            Some(SubmissionStatus::Failed { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn resend_failed_reports_still_failing_ids() {
+        let provider = FlakyProvider {
+            fail_count: u32::MAX,
+            attempts: AtomicU32::new(0),
+        };
+        let mut queue = SubmissionQueue::new(fast_backoff(3));
+        let id = queue.submit(&provider, request()).await;
+
+        let summary = queue.resend_failed(&provider).await;
+        assert_eq!(summary.attempted, 1);
+        assert_eq!(summary.succeeded, 0);
+        assert_eq!(summary.still_failing, vec![id]);
+    }
+
+    #[test]
+    fn unknown_id_has_no_status() {
+        let queue = SubmissionQueue::new(fast_backoff(3));
+        assert!(queue.status(42).is_none());
+    }
+}
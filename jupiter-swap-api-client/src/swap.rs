@@ -0,0 +1,36 @@
+//! Request/response types for turning a `QuoteResponse` into the instructions needed
+//! to execute it on-chain.
+
+use serde::{Deserialize, Serialize};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::quote::QuoteResponse;
+use crate::serde_helpers::field_as_string;
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+/// Request payload for building the instructions that execute a previously obtained quote.
+pub struct SwapRequest {
+    /// The public key of the wallet that will perform the swap.
+    #[serde(with = "field_as_string")]
+    pub user_public_key: Pubkey,
+    /// The quote to build instructions for, as returned by `quote`.
+    pub quote_response: QuoteResponse,
+    /// Wraps and unwraps native SOL automatically, so the wallet doesn't need a
+    /// pre-existing wrapped SOL account.
+    pub wrap_and_unwrap_sol: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+/// The instructions (and any address lookup tables they rely on) needed to execute a swap.
+pub struct SwapInstructionsResponse {
+    /// Instructions to run before the swap (e.g. creating token accounts).
+    pub setup_instructions: Vec<Instruction>,
+    /// The instruction that actually performs the swap.
+    pub swap_instruction: Instruction,
+    /// Instruction to run after the swap (e.g. closing a temporary wrapped SOL account).
+    pub cleanup_instruction: Option<Instruction>,
+    /// Address lookup tables referenced by the instructions above.
+    pub address_lookup_table_addresses: Vec<Pubkey>,
+}
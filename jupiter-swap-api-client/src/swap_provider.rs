@@ -0,0 +1,28 @@
+//! Abstraction over swap-quote backends, so callers aren't hard-wired to Jupiter's
+//! aggregator specifically.
+
+use anyhow::Result;
+use async_trait::async_trait;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::quote::{QuoteRequest, QuoteResponse};
+use crate::swap::SwapInstructionsResponse;
+
+/// A backend capable of quoting and building instructions for a token swap.
+#[async_trait]
+pub trait SwapProvider: Send + Sync {
+    /// A short, human-readable label identifying this provider (e.g. "Jupiter", "Sanctum").
+    /// Winning quotes are tagged with this label so `swap_instructions` can later route
+    /// back to the provider that produced them.
+    fn label(&self) -> &str;
+
+    /// Requests a quote for `req` from this provider.
+    async fn quote(&self, req: &QuoteRequest) -> Result<QuoteResponse>;
+
+    /// Builds the instructions needed to execute `quote_response` for `user_public_key`.
+    async fn swap_instructions(
+        &self,
+        quote_response: &QuoteResponse,
+        user_public_key: Pubkey,
+    ) -> Result<SwapInstructionsResponse>;
+}